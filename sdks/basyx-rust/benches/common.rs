@@ -0,0 +1,23 @@
+use std::fs;
+use std::path::PathBuf;
+
+pub fn get_datasets_dir() -> PathBuf {
+    let dir = std::env::var("DATASETS_DIR").expect("DATASETS_DIR not set");
+    PathBuf::from(dir)
+}
+
+pub fn get_dataset_files() -> Vec<(String, String)> {
+    let dir = get_datasets_dir();
+    let mut datasets = Vec::new();
+    for entry in fs::read_dir(&dir).expect("Failed to read DATASETS_DIR") {
+        let entry = entry.expect("Failed to read dir entry");
+        let path = entry.path();
+        if path.extension().map_or(false, |e| e == "json") {
+            let name = path.file_stem().unwrap().to_string_lossy().to_string();
+            let content = fs::read_to_string(&path).expect("Failed to read dataset");
+            datasets.push((name, content));
+        }
+    }
+    datasets.sort_by(|a, b| a.0.cmp(&b.0));
+    datasets
+}
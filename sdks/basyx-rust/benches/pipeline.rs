@@ -1,28 +1,9 @@
-use basyx_rs::Environment;
-use criterion::{criterion_group, criterion_main, Criterion};
-use std::fs;
-use std::path::PathBuf;
-
-fn get_datasets_dir() -> PathBuf {
-    let dir = std::env::var("DATASETS_DIR").expect("DATASETS_DIR not set");
-    PathBuf::from(dir)
-}
+#[path = "common.rs"]
+mod common;
 
-fn get_dataset_files() -> Vec<(String, String)> {
-    let dir = get_datasets_dir();
-    let mut datasets = Vec::new();
-    for entry in fs::read_dir(&dir).expect("Failed to read DATASETS_DIR") {
-        let entry = entry.expect("Failed to read dir entry");
-        let path = entry.path();
-        if path.extension().map_or(false, |e| e == "json") {
-            let name = path.file_stem().unwrap().to_string_lossy().to_string();
-            let content = fs::read_to_string(&path).expect("Failed to read dataset");
-            datasets.push((name, content));
-        }
-    }
-    datasets.sort_by(|a, b| a.0.cmp(&b.0));
-    datasets
-}
+use basyx_rs::Environment;
+use common::get_dataset_files;
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
 
 fn bench_deserialize(c: &mut Criterion) {
     let datasets = get_dataset_files();
@@ -34,6 +15,30 @@ fn bench_deserialize(c: &mut Criterion) {
                 std::hint::black_box(env);
             });
         });
+
+        let bytes = json_str.as_bytes().to_vec();
+
+        group.bench_function(format!("{name}_simd_borrowed"), |b| {
+            b.iter_batched(
+                || bytes.clone(),
+                |mut buf| {
+                    let value = simd_json::to_borrowed_value(&mut buf).unwrap();
+                    std::hint::black_box(value);
+                },
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_function(format!("{name}_simd_env"), |b| {
+            b.iter_batched(
+                || bytes.clone(),
+                |mut buf| {
+                    let env: Environment = simd_json::from_slice(&mut buf).unwrap();
+                    std::hint::black_box(env);
+                },
+                BatchSize::SmallInput,
+            );
+        });
     }
     group.finish();
 }
@@ -0,0 +1,41 @@
+#[path = "common.rs"]
+mod common;
+
+use basyx_rs::Environment;
+use common::get_dataset_files;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+const STREAM_LEN: usize = 8;
+
+fn bench_streaming_deserialize(c: &mut Criterion) {
+    let datasets = get_dataset_files();
+    let mut group = c.benchmark_group("streaming_deserialize");
+    for (name, json_str) in &datasets {
+        let concatenated: String = std::iter::repeat(json_str.as_str())
+            .take(STREAM_LEN)
+            .collect();
+
+        group.bench_function(BenchmarkId::new(name, "stream"), |b| {
+            b.iter(|| {
+                let envs: Vec<Environment> = serde_json::Deserializer::from_str(&concatenated)
+                    .into_iter::<Environment>()
+                    .map(|result| result.unwrap())
+                    .collect();
+                std::hint::black_box(envs);
+            });
+        });
+
+        group.bench_function(BenchmarkId::new(name, "separate"), |b| {
+            b.iter(|| {
+                let envs: Vec<Environment> = (0..STREAM_LEN)
+                    .map(|_| serde_json::from_str(json_str).unwrap())
+                    .collect();
+                std::hint::black_box(envs);
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_streaming_deserialize);
+criterion_main!(benches);
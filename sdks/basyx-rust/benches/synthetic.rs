@@ -0,0 +1,56 @@
+use basyx_rs::Environment;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+
+fn make_environment(num_submodels: usize, elements_per_submodel: usize) -> Environment {
+    let submodels: Vec<serde_json::Value> = (0..num_submodels)
+        .map(|i| {
+            let elements: Vec<serde_json::Value> = (0..elements_per_submodel)
+                .map(|j| {
+                    serde_json::json!({
+                        "modelType": "Property",
+                        "idShort": format!("prop_{j}"),
+                        "valueType": "xs:string",
+                        "value": format!("value_{i}_{j}"),
+                    })
+                })
+                .collect();
+            serde_json::json!({
+                "modelType": "Submodel",
+                "id": format!("https://example.com/submodels/{i}"),
+                "idShort": format!("submodel_{i}"),
+                "submodelElements": elements,
+            })
+        })
+        .collect();
+
+    let env_json = serde_json::json!({
+        "assetAdministrationShells": [],
+        "submodels": submodels,
+        "conceptDescriptions": [],
+    });
+
+    serde_json::from_value(env_json).expect("synthetic environment should deserialize")
+}
+
+fn bench_synthetic_deserialize(c: &mut Criterion) {
+    let mut group = c.benchmark_group("synthetic");
+    let sweep: Vec<(usize, usize)> = vec![(1, 10), (10, 10), (10, 100), (100, 100), (100, 1000)];
+
+    for (num_submodels, elements_per_submodel) in sweep {
+        let env = make_environment(num_submodels, elements_per_submodel);
+        let json_str = serde_json::to_string(&env).unwrap();
+        let n = (num_submodels * elements_per_submodel) as u64;
+
+        group.throughput(Throughput::Bytes(json_str.len() as u64));
+        group.bench_with_input(BenchmarkId::new("deserialize", n), &json_str, |b, json_str| {
+            b.iter(|| {
+                let env: Environment = serde_json::from_str(json_str).unwrap();
+                std::hint::black_box(env);
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_synthetic_deserialize);
+criterion_main!(benches);
@@ -0,0 +1,159 @@
+#[path = "../criterion_report.rs"]
+mod criterion_report;
+
+use criterion_report::{collect_measurements, BenchMeasurement};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs::{self, OpenOptions};
+use std::io::{BufRead, BufReader, Write};
+use std::path::PathBuf;
+use std::process::Command;
+
+const DEFAULT_REGRESSION_THRESHOLD: f64 = 0.05;
+
+#[derive(Debug, Serialize, Deserialize, Clone)]
+struct HistoryRecord {
+    group_name: String,
+    bench_name: String,
+    input: String,
+    commit_hash: String,
+    commit_timestamp: String,
+    mean_ns: f64,
+}
+
+fn criterion_dir() -> PathBuf {
+    std::env::var("CRITERION_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("target/criterion"))
+}
+
+fn history_path() -> PathBuf {
+    std::env::var("BENCH_HISTORY_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("bench_history.jsonl"))
+}
+
+fn regression_threshold() -> f64 {
+    std::env::var("BENCH_REGRESSION_THRESHOLD")
+        .ok()
+        .and_then(|v| v.parse().ok())
+        .unwrap_or(DEFAULT_REGRESSION_THRESHOLD)
+}
+
+fn git_commit_hash() -> String {
+    let output = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .expect("failed to run git rev-parse");
+    String::from_utf8(output.stdout)
+        .expect("git output was not utf-8")
+        .trim()
+        .to_string()
+}
+
+fn git_commit_timestamp() -> String {
+    // `%cI` always shows the committer's original timezone offset, ignoring
+    // `--date`. The `-local` date modes are the only way to get git to
+    // convert that instant into a different zone, so force it to UTC via the
+    // `TZ` env var rather than persisting timestamps with mixed offsets.
+    let output = Command::new("git")
+        .args([
+            "show",
+            "-s",
+            "--date=iso-strict-local",
+            "--format=%cd",
+            "HEAD",
+        ])
+        .env("TZ", "UTC")
+        .output()
+        .expect("failed to run git show");
+    String::from_utf8(output.stdout)
+        .expect("git output was not utf-8")
+        .trim()
+        .to_string()
+}
+
+fn load_history(path: &PathBuf) -> Vec<HistoryRecord> {
+    let Ok(file) = fs::File::open(path) else {
+        return Vec::new();
+    };
+    BufReader::new(file)
+        .lines()
+        .map(|line| line.expect("failed to read history line"))
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| serde_json::from_str(&line).expect("failed to parse history record"))
+        .collect()
+}
+
+fn key(group_name: &str, bench_name: &str, input: &str) -> String {
+    format!("{group_name}/{bench_name}/{input}")
+}
+
+fn most_recent_by_key(history: &[HistoryRecord]) -> HashMap<String, &HistoryRecord> {
+    let mut latest: HashMap<String, &HistoryRecord> = HashMap::new();
+    for record in history {
+        latest.insert(key(&record.group_name, &record.bench_name, &record.input), record);
+    }
+    latest
+}
+
+fn main() {
+    let commit_hash = git_commit_hash();
+    let commit_timestamp = git_commit_timestamp();
+
+    let measurements: Vec<BenchMeasurement> =
+        collect_measurements(&criterion_dir(), &commit_hash, &commit_timestamp)
+            .expect("failed to read Criterion output");
+
+    if measurements.is_empty() {
+        eprintln!("bench_history: no Criterion measurements found, nothing to record");
+        return;
+    }
+
+    let path = history_path();
+    let history = load_history(&path);
+    let previous_by_key = most_recent_by_key(&history);
+    let threshold = regression_threshold();
+    let mut regressed = false;
+
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(&path)
+        .expect("failed to open bench history file");
+
+    for measurement in &measurements {
+        let record = HistoryRecord {
+            group_name: measurement.id.group_name.clone(),
+            bench_name: measurement.id.bench_name.clone(),
+            input: measurement.id.params.input.clone(),
+            commit_hash: measurement.id.params.commit_hash.clone(),
+            commit_timestamp: measurement.id.params.commit_timestamp.clone(),
+            mean_ns: measurement.mean_ns,
+        };
+
+        if let Some(previous) = previous_by_key.get(&key(&record.group_name, &record.bench_name, &record.input)) {
+            let slowdown = (record.mean_ns - previous.mean_ns) / previous.mean_ns;
+            if slowdown > threshold {
+                eprintln!(
+                    "bench_history: REGRESSION {}/{}/{}: {:.1}% slower than commit {} ({:.0}ns -> {:.0}ns)",
+                    record.group_name,
+                    record.bench_name,
+                    record.input,
+                    slowdown * 100.0,
+                    previous.commit_hash,
+                    previous.mean_ns,
+                    record.mean_ns,
+                );
+                regressed = true;
+            }
+        }
+
+        let line = serde_json::to_string(&record).expect("failed to serialize history record");
+        writeln!(file, "{line}").expect("failed to append history record");
+    }
+
+    if regressed {
+        std::process::exit(1);
+    }
+}
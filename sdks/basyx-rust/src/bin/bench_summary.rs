@@ -0,0 +1,71 @@
+#[path = "../criterion_report.rs"]
+mod criterion_report;
+
+use criterion_report::{collect_measurements, BenchMeasurement};
+use std::fs;
+use std::path::PathBuf;
+
+fn criterion_dir() -> PathBuf {
+    std::env::var("CRITERION_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("target/criterion"))
+}
+
+fn summary_path() -> PathBuf {
+    std::env::var("BENCH_SUMMARY_FILE")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("bench_summary.md"))
+}
+
+fn bytes_per_sec(bytes: u64, mean_ns: f64) -> f64 {
+    bytes as f64 / (mean_ns / 1_000_000_000.0)
+}
+
+fn render_table(measurements: &[BenchMeasurement]) -> String {
+    let mut rows = measurements.to_vec();
+    rows.sort_by(|a, b| {
+        a.id.bench_name
+            .cmp(&b.id.bench_name)
+            .then(a.id.group_name.cmp(&b.id.group_name))
+            .then(a.id.params.input.cmp(&b.id.params.input))
+    });
+
+    let mut out = String::new();
+    out.push_str("| Dataset | Operation | Mean | Median | Bytes/sec |\n");
+    out.push_str("|---|---|---|---|---|\n");
+    for m in &rows {
+        let bytes_per_sec = m
+            .throughput_bytes
+            .map(|bytes| format!("{:.0}", bytes_per_sec(bytes, m.mean_ns)))
+            .unwrap_or_else(|| "-".to_string());
+        out.push_str(&format!(
+            "| {} | {}/{} | {:.2} us | {:.2} us | {} |\n",
+            m.id.bench_name,
+            m.id.group_name,
+            m.id.params.input,
+            m.mean_ns / 1000.0,
+            m.median_ns / 1000.0,
+            bytes_per_sec,
+        ));
+    }
+    out
+}
+
+fn main() {
+    let measurements =
+        collect_measurements(&criterion_dir(), "", "").expect("failed to read Criterion output");
+
+    if measurements.is_empty() {
+        eprintln!("bench_summary: no Criterion measurements found, nothing to summarize");
+        return;
+    }
+
+    let table = render_table(&measurements);
+
+    let path = summary_path();
+    fs::write(&path, &table).expect("failed to write bench summary file");
+
+    if std::env::var("BENCH_SUMMARY_STDOUT").is_ok() {
+        print!("{table}");
+    }
+}
@@ -0,0 +1,169 @@
+use serde::Deserialize;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Identifies a single Criterion benchmark, reconstructed from the
+/// `<group>/<bench>/<input>` directory layout under `target/criterion`.
+#[derive(Debug, Clone)]
+pub struct BenchId {
+    pub group_name: String,
+    pub bench_name: String,
+    pub params: BenchParams,
+}
+
+/// The commit this measurement was taken at, plus the input it was run with.
+#[derive(Debug, Clone)]
+pub struct BenchParams {
+    pub commit_hash: String,
+    pub commit_timestamp: String,
+    pub input: String,
+}
+
+#[derive(Debug, Clone)]
+pub struct BenchMeasurement {
+    pub id: BenchId,
+    pub mean_ns: f64,
+    pub median_ns: f64,
+    /// The payload size Criterion was told about via `group.throughput(..)`,
+    /// if the benchmark set one. `None` for benchmarks that never called it.
+    pub throughput_bytes: Option<u64>,
+}
+
+#[derive(Deserialize)]
+struct Estimate {
+    point_estimate: f64,
+}
+
+#[derive(Deserialize)]
+struct Estimates {
+    mean: Estimate,
+    median: Estimate,
+}
+
+#[derive(Deserialize)]
+struct BenchmarkJson {
+    throughput: Option<Throughput>,
+}
+
+#[derive(Deserialize)]
+enum Throughput {
+    Bytes(u64),
+    BytesDecimal(u64),
+    Elements(u64),
+}
+
+impl Throughput {
+    fn bytes(&self) -> Option<u64> {
+        match self {
+            Throughput::Bytes(n) | Throughput::BytesDecimal(n) => Some(*n),
+            Throughput::Elements(_) => None,
+        }
+    }
+}
+
+/// Splits a Criterion bench id into `BenchId` parts. Plain `group/bench`
+/// ids (e.g. `pipeline.rs`'s `deserialize/<dataset>`, built from a single
+/// string handed straight to `bench_function`) have no explicit input, so
+/// `input` is left empty. Ids built from a real `BenchmarkId::new(bench,
+/// input)` (e.g. `formats.rs`'s `deserialize/<dataset>/<format>`,
+/// `synthetic.rs`'s `synthetic/deserialize/<n>`) carry the input as the
+/// third segment — note that handing `bench_function` a single string
+/// containing a `/` does NOT produce this layout, since Criterion sanitizes
+/// it into one filename-safe segment instead of splitting it in two.
+/// Panics if the id doesn't split into two or three segments, since that
+/// means the `target/criterion` layout no longer matches what this tool
+/// understands.
+pub fn parse_bench_id(raw_id: &str, commit_hash: &str, commit_timestamp: &str) -> BenchId {
+    let parts: Vec<&str> = raw_id.split('/').collect();
+    let (group_name, bench_name, input) = match parts.as_slice() {
+        [group, bench] => (*group, *bench, ""),
+        [group, bench, input] => (*group, *bench, *input),
+        _ => panic!(
+            "unexpected Criterion bench id `{raw_id}`: expected 2 or 3 `/`-separated segments, got {}",
+            parts.len()
+        ),
+    };
+    BenchId {
+        group_name: group_name.to_string(),
+        bench_name: bench_name.to_string(),
+        params: BenchParams {
+            commit_hash: commit_hash.to_string(),
+            commit_timestamp: commit_timestamp.to_string(),
+            input: input.to_string(),
+        },
+    }
+}
+
+/// Walks a `target/criterion` directory and loads the mean/median estimate
+/// for every benchmark found.
+pub fn collect_measurements(
+    criterion_dir: &Path,
+    commit_hash: &str,
+    commit_timestamp: &str,
+) -> io::Result<Vec<BenchMeasurement>> {
+    let mut measurements = Vec::new();
+    collect_measurements_recursive(
+        criterion_dir,
+        criterion_dir,
+        commit_hash,
+        commit_timestamp,
+        &mut measurements,
+    )?;
+    Ok(measurements)
+}
+
+fn collect_measurements_recursive(
+    root: &Path,
+    dir: &Path,
+    commit_hash: &str,
+    commit_timestamp: &str,
+    out: &mut Vec<BenchMeasurement>,
+) -> io::Result<()> {
+    let estimates_path = dir.join("new").join("estimates.json");
+    if estimates_path.is_file() {
+        let raw_id = dir
+            .strip_prefix(root)
+            .unwrap()
+            .to_string_lossy()
+            .replace(std::path::MAIN_SEPARATOR, "/");
+        let id = parse_bench_id(&raw_id, commit_hash, commit_timestamp);
+        let contents = fs::read_to_string(&estimates_path)?;
+        let estimates: Estimates = serde_json::from_str(&contents)?;
+
+        let benchmark_json_path = dir.join("new").join("benchmark.json");
+        let throughput_bytes = match fs::read_to_string(&benchmark_json_path) {
+            Ok(contents) => {
+                let benchmark: BenchmarkJson =
+                    serde_json::from_str(&contents).unwrap_or_else(|e| {
+                        panic!("failed to parse {}: {e}", benchmark_json_path.display())
+                    });
+                benchmark
+                    .throughput
+                    .and_then(|throughput| throughput.bytes())
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => None,
+            Err(e) => panic!("failed to read {}: {e}", benchmark_json_path.display()),
+        };
+
+        out.push(BenchMeasurement {
+            id,
+            mean_ns: estimates.mean.point_estimate,
+            median_ns: estimates.median.point_estimate,
+            throughput_bytes,
+        });
+    }
+
+    for entry in fs::read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        let is_leaf_output_dir = path
+            .file_name()
+            .map_or(false, |n| n == "new" || n == "base" || n == "report");
+        if path.is_dir() && !is_leaf_output_dir {
+            collect_measurements_recursive(root, &path, commit_hash, commit_timestamp, out)?;
+        }
+    }
+
+    Ok(())
+}